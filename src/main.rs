@@ -1,4 +1,4 @@
-use sip_it::{Error, Token};
+use sip_it::{Error, Node};
 
 mod sip_it;
 
@@ -7,12 +7,12 @@ fn main() {
         let mut code = String::new();
         std::io::stdin().read_line(&mut code).unwrap();
 
-        let (tokens, err): (Vec<Token>, Option<Error>) = sip_it::run("<stdin>", &*code);
+        let (node, err): (Option<Node>, Option<Error>) = sip_it::run("<stdin>", &*code);
 
-        if err.is_none() {
-            println!("{:?}", tokens)
+        if let Some(err) = err {
+            println!("{}", err)
         } else {
-            println!("{}", err.unwrap())
+            println!("{:?}", node.unwrap())
         }
     }
 }
@@ -2,10 +2,20 @@ use std::fmt::{self, write};
 
 // CONSTANTS
 const DIGITS: &str = "0123456789";
+const KEYWORDS: &[&str] = &["let", "if", "for"];
+
+fn is_in_base(c: char, base: u32) -> bool {
+    match base {
+        2 => matches!(c, '0' | '1'),
+        8 => ('0'..='7').contains(&c),
+        16 => c.is_ascii_hexdigit(),
+        _ => c.is_ascii_digit(),
+    }
+}
 
 // ERRORS
-#[derive(Debug)]
-struct Position {
+#[derive(Debug, Clone)]
+pub(crate) struct Position {
     idx: isize,
     ln: usize,
     col: isize,
@@ -41,10 +51,13 @@ impl Position {
     }
 }
 
-#[derive(Debug)]
-enum TokenType {
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TokenType {
     INT(isize),
     FLOAT(f64),
+    STRING(String),
+    IDENT(String),
+    KEYWORD(String),
     PLUS,
     MINUS,
     MUL,
@@ -53,14 +66,31 @@ enum TokenType {
     RPAREN,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Token {
     type_: TokenType,
+    pos_start: Position,
+    pos_end: Position,
+    suffix: Option<String>,
 }
 
 impl Token {
-    fn new(type_: TokenType) -> Token {
-        Token { type_ }
+    fn new(type_: TokenType, pos_start: Position, pos_end: Position) -> Token {
+        Token::with_suffix(type_, pos_start, pos_end, None)
+    }
+
+    fn with_suffix(
+        type_: TokenType,
+        pos_start: Position,
+        pos_end: Position,
+        suffix: Option<String>,
+    ) -> Token {
+        Token {
+            type_,
+            pos_start,
+            pos_end,
+            suffix,
+        }
     }
 }
 
@@ -69,6 +99,9 @@ impl fmt::Display for Token {
         match &self.type_ {
             TokenType::INT(int_val) => write!(f, "INT({})", int_val),
             TokenType::FLOAT(float_val) => write!(f, "FLOAT({})", float_val),
+            TokenType::STRING(str_val) => write!(f, "STRING({})", str_val),
+            TokenType::IDENT(name) => write!(f, "IDENT({})", name),
+            TokenType::KEYWORD(name) => write!(f, "KEYWORD({})", name),
             TokenType::PLUS => write!(f, "PLUS"),
             TokenType::MINUS => write!(f, "MINUS"),
             TokenType::MUL => write!(f, "MULTIPLY"),
@@ -112,33 +145,51 @@ impl Lexer {
         while let Some(current_char) = self.current_char {
             if current_char.is_whitespace() {
                 self.advance();
+            } else if current_char == '#' {
+                self.skip_comment();
             } else if DIGITS.contains(current_char) {
-                tokens.push(self.make_number());
+                match self.make_number() {
+                    Ok(token) => tokens.push(token),
+                    Err(error) => return (vec![], Some(error)),
+                }
+            } else if current_char.is_alphabetic() || current_char == '_' {
+                tokens.push(self.make_identifier());
+            } else if current_char == '"' {
+                match self.make_string() {
+                    Ok(token) => tokens.push(token),
+                    Err(error) => return (vec![], Some(error)),
+                }
             } else {
                 match current_char {
                     '+' => {
-                        tokens.push(Token::new(TokenType::PLUS));
+                        let pos_start = self.pos.copy();
                         self.advance();
+                        tokens.push(Token::new(TokenType::PLUS, pos_start, self.pos.copy()));
                     }
                     '-' => {
-                        tokens.push(Token::new(TokenType::MINUS));
+                        let pos_start = self.pos.copy();
                         self.advance();
+                        tokens.push(Token::new(TokenType::MINUS, pos_start, self.pos.copy()));
                     }
                     '*' => {
-                        tokens.push(Token::new(TokenType::MUL));
+                        let pos_start = self.pos.copy();
                         self.advance();
+                        tokens.push(Token::new(TokenType::MUL, pos_start, self.pos.copy()));
                     }
                     '/' => {
-                        tokens.push(Token::new(TokenType::DIV));
+                        let pos_start = self.pos.copy();
                         self.advance();
+                        tokens.push(Token::new(TokenType::DIV, pos_start, self.pos.copy()));
                     }
                     '(' => {
-                        tokens.push(Token::new(TokenType::LPAREN));
+                        let pos_start = self.pos.copy();
                         self.advance();
+                        tokens.push(Token::new(TokenType::LPAREN, pos_start, self.pos.copy()));
                     }
                     ')' => {
-                        tokens.push(Token::new(TokenType::RPAREN));
+                        let pos_start = self.pos.copy();
                         self.advance();
+                        tokens.push(Token::new(TokenType::RPAREN, pos_start, self.pos.copy()));
                     }
                     _ => {
                         let pos_start = self.pos.copy();
@@ -147,11 +198,10 @@ impl Lexer {
                         self.advance();
                         return (
                             vec![],
-                            Some(Error::new(
+                            Some(Error::at(
                                 "Illegal Char Error",
-                                pos_start,
-                                self.pos.copy(),
-                                &char_str,
+                                &pos_start,
+                                Some(&char_str),
                             )),
                         );
                     }
@@ -162,7 +212,71 @@ impl Lexer {
         (tokens, None)
     }
 
-    fn make_number(&mut self) -> Token {
+    fn skip_comment(&mut self) {
+        while let Some(current_char) = self.current_char {
+            self.advance();
+
+            if current_char == '\n' {
+                break;
+            }
+        }
+    }
+
+    fn make_number(&mut self) -> Result<Token, Error> {
+        let pos_start = self.pos.copy();
+
+        if self.current_char == Some('0') {
+            let base = match self.text.chars().nth((self.pos.idx + 1) as usize) {
+                Some('x') => Some(16),
+                Some('o') => Some(8),
+                Some('b') => Some(2),
+                _ => None,
+            };
+
+            if let Some(base) = base {
+                self.advance(); // consume the '0'
+                self.advance(); // consume the base prefix letter
+
+                let mut digits = String::new();
+
+                while let Some(current_char) = self.current_char {
+                    if is_in_base(current_char, base) {
+                        digits.push(current_char);
+                        self.advance();
+                    } else {
+                        break;
+                    }
+                }
+
+                if digits.is_empty() {
+                    return Err(Error::at(
+                        "Invalid Syntax Error: expected digits after base prefix",
+                        &pos_start,
+                        None,
+                    ));
+                }
+
+                let int_val = match isize::from_str_radix(&digits, base) {
+                    Ok(int_val) => int_val,
+                    Err(_) => {
+                        return Err(Error::at(
+                            "Invalid Syntax Error: radix integer out of range",
+                            &pos_start,
+                            Some(&digits),
+                        ));
+                    }
+                };
+                let suffix = self.make_suffix();
+
+                return Ok(Token::with_suffix(
+                    TokenType::INT(int_val),
+                    pos_start,
+                    self.pos.copy(),
+                    suffix,
+                ));
+            }
+        }
+
         let mut num_str = String::new();
         let mut dot_count = 0;
 
@@ -182,51 +296,546 @@ impl Lexer {
             self.advance();
         }
 
+        let suffix = self.make_suffix();
+
         if dot_count == 0 {
-            Token::new(TokenType::INT(
-                num_str.parse().expect("A valid integer was expected"),
-            ))
+            match num_str.parse() {
+                Ok(int_val) => Ok(Token::with_suffix(
+                    TokenType::INT(int_val),
+                    pos_start,
+                    self.pos.copy(),
+                    suffix,
+                )),
+                Err(_) => Err(Error::at(
+                    "Invalid Syntax Error: integer literal out of range",
+                    &pos_start,
+                    Some(&num_str),
+                )),
+            }
         } else {
-            Token::new(TokenType::FLOAT(
-                num_str.parse().expect("A valid float was expected"),
-            ))
+            match num_str.parse() {
+                Ok(float_val) => Ok(Token::with_suffix(
+                    TokenType::FLOAT(float_val),
+                    pos_start,
+                    self.pos.copy(),
+                    suffix,
+                )),
+                Err(_) => Err(Error::at(
+                    "Invalid Syntax Error: float literal could not be parsed",
+                    &pos_start,
+                    Some(&num_str),
+                )),
+            }
         }
     }
+
+    fn make_suffix(&mut self) -> Option<String> {
+        let mut suffix = String::new();
+
+        while let Some(current_char) = self.current_char {
+            if current_char.is_alphanumeric() || current_char == '_' {
+                suffix.push(current_char);
+                self.advance();
+            } else {
+                break;
+            }
+        }
+
+        if suffix.is_empty() {
+            None
+        } else {
+            Some(suffix)
+        }
+    }
+
+    fn make_identifier(&mut self) -> Token {
+        let mut ident_str = String::new();
+        let pos_start = self.pos.copy();
+
+        while let Some(current_char) = self.current_char {
+            if current_char.is_alphanumeric() || current_char == '_' {
+                ident_str.push(current_char);
+            } else {
+                break;
+            }
+
+            self.advance();
+        }
+
+        let pos_end = self.pos.copy();
+
+        if KEYWORDS.contains(&&*ident_str) {
+            Token::new(TokenType::KEYWORD(ident_str), pos_start, pos_end)
+        } else {
+            Token::new(TokenType::IDENT(ident_str), pos_start, pos_end)
+        }
+    }
+
+    fn make_string(&mut self) -> Result<Token, Error> {
+        let mut str_val = String::new();
+        let pos_start = self.pos.copy();
+        let mut escaped = false;
+
+        self.advance(); // consume the opening quote
+
+        while let Some(current_char) = self.current_char {
+            if escaped {
+                str_val.push(match current_char {
+                    'n' => '\n',
+                    't' => '\t',
+                    '\\' => '\\',
+                    '"' => '"',
+                    other => other,
+                });
+                escaped = false;
+            } else if current_char == '\\' {
+                escaped = true;
+            } else if current_char == '"' {
+                self.advance(); // consume the closing quote
+                return Ok(Token::new(
+                    TokenType::STRING(str_val),
+                    pos_start,
+                    self.pos.copy(),
+                ));
+            } else {
+                str_val.push(current_char);
+            }
+
+            self.advance();
+        }
+
+        Err(Error::at("Unterminated String", &pos_start, Some(&str_val)))
+    }
 }
 
+// AST
+#[derive(Debug, PartialEq)]
+pub enum Node {
+    Int(isize),
+    Float(f64),
+    BinOp(Box<Node>, TokenType, Box<Node>),
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Node::Int(val) => write!(f, "{}", val),
+            Node::Float(val) => write!(f, "{}", val),
+            Node::BinOp(left, op, right) => write!(f, "({}, {:?}, {})", left, op, right),
+        }
+    }
+}
+
+// PARSER
+struct Parser {
+    fn_name: String,
+    ftxt: String,
+    tokens: Vec<Token>,
+    tok_idx: isize,
+    current_tok: Option<Token>,
+    prev_tok_end: Option<Position>,
+}
+
+impl Parser {
+    fn new(fn_name: &str, ftxt: &str, tokens: Vec<Token>) -> Parser {
+        let mut parser = Parser {
+            fn_name: fn_name.to_string(),
+            ftxt: ftxt.to_string(),
+            tokens,
+            tok_idx: -1,
+            current_tok: None,
+            prev_tok_end: None,
+        };
+
+        parser.advance();
+        parser
+    }
+
+    fn advance(&mut self) -> Option<&Token> {
+        if let Some(tok) = &self.current_tok {
+            self.prev_tok_end = Some(tok.pos_end.copy());
+        }
+
+        self.tok_idx += 1;
+
+        self.current_tok = if self.tok_idx < self.tokens.len() as isize {
+            Some(self.tokens[self.tok_idx as usize].clone())
+        } else {
+            None
+        };
+
+        self.current_tok.as_ref()
+    }
+
+    fn unexpected_token_error(&self, details: &str) -> Error {
+        let message = format!("Invalid Syntax Error: {}", details);
+
+        match (&self.current_tok, &self.prev_tok_end) {
+            (Some(tok), _) => Error::at(message, &tok.pos_start, Some(&tok.to_string())),
+            (None, Some(pos)) => Error::at(message, pos, None),
+            (None, None) => {
+                let pos = Position::new(-1, 0, -1, &*self.fn_name, &*self.ftxt);
+                Error::at(message, &pos, None)
+            }
+        }
+    }
+
+    fn parse(&mut self) -> Result<Node, Error> {
+        let expr = self.parse_expr()?;
+
+        if self.current_tok.is_some() {
+            return Err(self.unexpected_token_error("Expected '+', '-', '*' or '/'"));
+        }
+
+        Ok(expr)
+    }
+
+    fn parse_factor(&mut self) -> Result<Node, Error> {
+        match self.current_tok.clone() {
+            Some(Token {
+                type_: TokenType::INT(val),
+                ..
+            }) => {
+                self.advance();
+                Ok(Node::Int(val))
+            }
+            Some(Token {
+                type_: TokenType::FLOAT(val),
+                ..
+            }) => {
+                self.advance();
+                Ok(Node::Float(val))
+            }
+            Some(Token {
+                type_: TokenType::LPAREN,
+                ..
+            }) => {
+                self.advance();
+                let expr = self.parse_expr()?;
+
+                match &self.current_tok {
+                    Some(Token {
+                        type_: TokenType::RPAREN,
+                        ..
+                    }) => {
+                        self.advance();
+                        Ok(expr)
+                    }
+                    _ => Err(self.unexpected_token_error("Expected ')'")),
+                }
+            }
+            _ => Err(self.unexpected_token_error("Expected int, float or '('")),
+        }
+    }
+
+    fn parse_term(&mut self) -> Result<Node, Error> {
+        let mut left = self.parse_factor()?;
+
+        while matches!(
+            self.current_tok.as_ref().map(|tok| &tok.type_),
+            Some(TokenType::MUL) | Some(TokenType::DIV)
+        ) {
+            let op = self.current_tok.clone().unwrap().type_;
+            self.advance();
+            let right = self.parse_factor()?;
+            left = Node::BinOp(Box::new(left), op, Box::new(right));
+        }
+
+        Ok(left)
+    }
+
+    fn parse_expr(&mut self) -> Result<Node, Error> {
+        let mut left = self.parse_term()?;
+
+        while matches!(
+            self.current_tok.as_ref().map(|tok| &tok.type_),
+            Some(TokenType::PLUS) | Some(TokenType::MINUS)
+        ) {
+            let op = self.current_tok.clone().unwrap().type_;
+            self.advance();
+            let right = self.parse_term()?;
+            left = Node::BinOp(Box::new(left), op, Box::new(right));
+        }
+
+        Ok(left)
+    }
+}
+
+#[derive(Debug)]
 pub struct Error {
-    type_: String,
-    pos_start: Position,
-    pos_end: Position,
-    details: String,
+    file_name: Option<String>,
+    line_number: Option<usize>,
+    token: Option<String>,
+    message: String,
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}: {}\nFile {}, line {}",
-            self.type_,
-            self.details,
-            self.pos_start.fn_name,
-            self.pos_start.ln + 1
-        )
+        match (&self.file_name, self.line_number) {
+            (Some(file_name), Some(line_number)) => write!(f, "{}:{}: ", file_name, line_number)?,
+            (Some(file_name), None) => write!(f, "{}: ", file_name)?,
+            (None, Some(line_number)) => write!(f, "line {}: ", line_number)?,
+            (None, None) => return write!(f, "{}", self.message),
+        }
+
+        if let Some(token) = &self.token {
+            write!(f, "near '{}': ", token)?;
+        }
+
+        write!(f, "{}", self.message)
     }
 }
 
+impl std::error::Error for Error {}
+
 impl Error {
-    fn new(type_: &str, pos_start: Position, pos_end: Position, details: &str) -> Error {
+    pub fn new(message: impl Into<String>) -> Error {
         Error {
-            type_: type_.to_string(),
-            pos_start,
-            pos_end,
-            details: details.to_string(),
+            file_name: None,
+            line_number: None,
+            token: None,
+            message: message.into(),
+        }
+    }
+
+    fn at(message: impl Into<String>, pos: &Position, token: Option<&str>) -> Error {
+        Error {
+            file_name: Some(pos.fn_name.clone()),
+            line_number: Some(pos.ln + 1),
+            token: token.map(|t| t.to_string()),
+            message: message.into(),
         }
     }
 }
 
 // RUN
-pub fn run(fn_name: &str, text: &str) -> (Vec<Token>, Option<Error>) {
+pub fn run(fn_name: &str, text: &str) -> (Option<Node>, Option<Error>) {
     let mut lexer = Lexer::new(fn_name, text);
-    lexer.make_tokens()
+    let (tokens, error) = lexer.make_tokens();
+
+    if let Some(error) = error {
+        return (None, Some(error));
+    }
+
+    let mut parser = Parser::new(fn_name, text, tokens);
+
+    match parser.parse() {
+        Ok(node) => (Some(node), None),
+        Err(error) => (None, Some(error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_escape_sequences() {
+        let (tokens, error) = Lexer::new("<test>", r#""a\nb\tc\\d\"e""#).make_tokens();
+
+        assert!(error.is_none());
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].type_, TokenType::STRING("a\nb\tc\\d\"e".to_string()));
+    }
+
+    #[test]
+    fn unterminated_string_is_an_error() {
+        let (tokens, error) = Lexer::new("<test>", r#""abc"#).make_tokens();
+
+        assert!(tokens.is_empty());
+        assert!(error.is_some());
+    }
+
+    #[test]
+    fn lexes_radix_prefixed_integers() {
+        let (tokens, error) = Lexer::new("<test>", "0xFF 0o17 0b1010").make_tokens();
+
+        assert!(error.is_none());
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[0].type_, TokenType::INT(255));
+        assert_eq!(tokens[1].type_, TokenType::INT(15));
+        assert_eq!(tokens[2].type_, TokenType::INT(10));
+    }
+
+    #[test]
+    fn empty_digit_run_after_base_prefix_is_an_error() {
+        let (tokens, error) = Lexer::new("<test>", "0x").make_tokens();
+
+        assert!(tokens.is_empty());
+        assert!(error.is_some());
+    }
+
+    #[test]
+    fn radix_literal_overflow_is_an_error_not_a_panic() {
+        let (tokens, error) = Lexer::new("<test>", "0xFFFFFFFFFFFFFFFFF").make_tokens();
+
+        assert!(tokens.is_empty());
+        assert!(error.is_some());
+    }
+
+    #[test]
+    fn decimal_literal_overflow_is_an_error_not_a_panic() {
+        let (tokens, error) = Lexer::new("<test>", "99999999999999999999999999").make_tokens();
+
+        assert!(tokens.is_empty());
+        assert!(error.is_some());
+    }
+
+    #[test]
+    fn captures_integer_and_float_suffixes() {
+        let (tokens, error) = Lexer::new("<test>", "10i64 3.0f32").make_tokens();
+
+        assert!(error.is_none());
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].type_, TokenType::INT(10));
+        assert_eq!(tokens[0].suffix, Some("i64".to_string()));
+        assert_eq!(tokens[1].type_, TokenType::FLOAT(3.0));
+        assert_eq!(tokens[1].suffix, Some("f32".to_string()));
+    }
+
+    #[test]
+    fn number_without_suffix_has_none() {
+        let (tokens, error) = Lexer::new("<test>", "42").make_tokens();
+
+        assert!(error.is_none());
+        assert_eq!(tokens[0].suffix, None);
+    }
+
+    #[test]
+    fn operator_span_covers_just_its_own_character() {
+        let (tokens, error) = Lexer::new("<test>", "1 * 2").make_tokens();
+
+        assert!(error.is_none());
+        assert_eq!(tokens[1].type_, TokenType::MUL);
+        assert_eq!(tokens[1].pos_start.col, 2);
+        assert_eq!(tokens[1].pos_end.col, 3);
+    }
+
+    #[test]
+    fn identifier_span_covers_only_its_own_characters() {
+        let (tokens, error) = Lexer::new("<test>", "foo bar").make_tokens();
+
+        assert!(error.is_none());
+        assert_eq!(tokens[0].type_, TokenType::IDENT("foo".to_string()));
+        assert_eq!(tokens[0].pos_start.col, 0);
+        assert_eq!(tokens[0].pos_end.col, 3);
+        assert_eq!(tokens[1].type_, TokenType::IDENT("bar".to_string()));
+        assert_eq!(tokens[1].pos_start.col, 4);
+        assert_eq!(tokens[1].pos_end.col, 7);
+    }
+
+    #[test]
+    fn keyword_is_not_confused_with_a_similarly_prefixed_identifier() {
+        let (tokens, error) = Lexer::new("<test>", "let let_x").make_tokens();
+
+        assert!(error.is_none());
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].type_, TokenType::KEYWORD("let".to_string()));
+        assert_eq!(tokens[1].type_, TokenType::IDENT("let_x".to_string()));
+    }
+
+    #[test]
+    fn line_comment_is_skipped_instead_of_erroring() {
+        let (tokens, error) = Lexer::new("<test>", "1 # this is a comment\n2").make_tokens();
+
+        assert!(error.is_none());
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].type_, TokenType::INT(1));
+        assert_eq!(tokens[1].type_, TokenType::INT(2));
+    }
+
+    #[test]
+    fn line_number_still_advances_across_a_comment() {
+        let (tokens, error) = Lexer::new("<test>", "1 # comment\n2").make_tokens();
+
+        assert!(error.is_none());
+        assert_eq!(tokens[0].pos_start.ln, 0);
+        assert_eq!(tokens[1].pos_start.ln, 1);
+    }
+
+    #[test]
+    fn error_display_with_only_a_message() {
+        let error = Error::new("something went wrong");
+
+        assert_eq!(error.to_string(), "something went wrong");
+    }
+
+    #[test]
+    fn error_display_with_position_and_token() {
+        let pos = Position::new(0, 0, 0, "<test>", "x");
+        let error = Error::at("Illegal Char Error", &pos, Some("x"));
+
+        assert_eq!(error.to_string(), "<test>:1: near 'x': Illegal Char Error");
+    }
+
+    #[test]
+    fn error_is_usable_as_a_std_error() {
+        fn takes_std_error(_err: &dyn std::error::Error) {}
+
+        let error = Error::new("boxed");
+        takes_std_error(&error);
+    }
+
+    #[test]
+    fn unclosed_paren_at_eof_reports_expected_rparen() {
+        let (node, error) = run("<test>", "(1 + 2");
+
+        assert!(node.is_none());
+        let error = error.expect("unclosed paren should fail to parse");
+        assert!(error.to_string().contains("Expected ')'"));
+    }
+
+    #[test]
+    fn multiplication_binds_tighter_than_addition() {
+        let (node, error) = run("<test>", "1 + 2 * 3");
+
+        assert!(error.is_none());
+        assert_eq!(
+            node.unwrap(),
+            Node::BinOp(
+                Box::new(Node::Int(1)),
+                TokenType::PLUS,
+                Box::new(Node::BinOp(
+                    Box::new(Node::Int(2)),
+                    TokenType::MUL,
+                    Box::new(Node::Int(3)),
+                )),
+            )
+        );
+    }
+
+    #[test]
+    fn parenthesized_group_overrides_precedence() {
+        let (node, error) = run("<test>", "(1 + 2) * 3");
+
+        assert!(error.is_none());
+        assert_eq!(
+            node.unwrap(),
+            Node::BinOp(
+                Box::new(Node::BinOp(
+                    Box::new(Node::Int(1)),
+                    TokenType::PLUS,
+                    Box::new(Node::Int(2)),
+                )),
+                TokenType::MUL,
+                Box::new(Node::Int(3)),
+            )
+        );
+    }
+
+    #[test]
+    fn trailing_operator_is_a_syntax_error() {
+        let (node, error) = run("<test>", "1 +");
+
+        assert!(node.is_none());
+        assert!(error.is_some());
+    }
+
+    #[test]
+    fn unclosed_paren_is_a_syntax_error() {
+        let (node, error) = run("<test>", "(1");
+
+        assert!(node.is_none());
+        assert!(error.is_some());
+    }
 }